@@ -2,6 +2,8 @@ use bevy::math::Vec3;
 use bevy::mesh::{Indices, Mesh, PrimitiveTopology};
 use bevy::asset::RenderAssetUsages;
 
+pub mod ops;
+
 /// A 2D or 3D mesh (`bevy::render::mesh::Mesh`) generated from a single-variable function
 /// `f(f32) -> f32`.
 #[derive(Debug, Clone, Copy)]
@@ -79,18 +81,22 @@ impl From<SingleVariableFunctionMesh> for Mesh {
                 // Place vertices.
                 let (mut x, mut z) = (j.x, j.y);
                 if amount_layers > 1 {
-                    (x, z) = (x.signum() * (x.abs() * i.y), z.signum() * (z.abs() * i.y));
+                    (x, z) = (
+                        ops::signum(x) * (x.abs() * i.y),
+                        ops::signum(z) * (z.abs() * i.y),
+                    );
                 }
                 let y = i.x;
 
                 // Create normals.
                 let mut normal_horizontally =
-                    Vec3::new(-j.slope_in_percentage.tan(), 0.0, 1.0).normalize();
+                    ops::normalize(Vec3::new(-ops::tan(j.slope_in_percentage), 0.0, 1.0));
 
                 if k >= amount / 2 {
                     normal_horizontally[2] = -normal_horizontally[2];
                 }
-                let normal_vertical = Vec3::new(1.0, -i.slope_in_percentage.tan(), 1.0).normalize();
+                let normal_vertical =
+                    ops::normalize(Vec3::new(1.0, -ops::tan(i.slope_in_percentage), 1.0));
                 let mut normals = [
                     normal_horizontally[0] / 3.0 * 2.0,
                     normal_vertical[1],
@@ -176,12 +182,12 @@ fn calculate_ring_of_vertices(
     let start = Position {
         x: x_start,
         y: f(x_start),
-        slope_in_percentage: ((f(x_start + delta) - f(x_start)) / (delta)).atan(),
+        slope_in_percentage: ops::atan((f(x_start + delta) - f(x_start)) / (delta)),
     };
     let end = Position {
         x: x_end,
         y: f(x_end),
-        slope_in_percentage: ((f(x_end) - f(x_end - delta)) / (delta)).atan(),
+        slope_in_percentage: ops::atan((f(x_end) - f(x_end - delta)) / (delta)),
     };
     let mut vec: Vec<Position> = Vec::with_capacity(vertices);
     let mut maximum = 0.0;
@@ -191,7 +197,7 @@ fn calculate_ring_of_vertices(
         let (mut index, mut max_slope_difference, mut max_x_difference) = (1, 0.0, 0.0);
         for j in 1..vec.len() {
             let new_x = vec[j - 1].x + (vec[j].x - vec[j - 1].x) / 2.0;
-            let new_m = ((f(new_x + delta) - f(new_x)) / (delta)).atan();
+            let new_m = ops::atan((f(new_x + delta) - f(new_x)) / (delta));
             let x_difference = vec[j].x - vec[j - 1].x;
             let slope_difference = (new_m - vec[j].slope_in_percentage).abs()
                 + (new_m - vec[j - 1].slope_in_percentage).abs();
@@ -208,7 +214,7 @@ fn calculate_ring_of_vertices(
             Position {
                 x: new_x,
                 y: f(new_x),
-                slope_in_percentage: ((f(new_x + delta) - f(new_x)) / (delta)).atan(),
+                slope_in_percentage: ops::atan((f(new_x + delta) - f(new_x)) / (delta)),
             },
         );
         if f(new_x) > maximum {
@@ -241,7 +247,7 @@ mod tests {
     use super::*;
 
     fn circle(x: f32) -> f32 {
-        (1.0 - x.powf(2.0)).powf(0.5)
+        ops::powf(1.0 - ops::powf(x, 2.0), 0.5)
     }
 
     fn square(_x: f32) -> f32 {
@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use bevy_single_variable_function_mesh::SingleVariableFunctionMesh;
+use bevy_single_variable_function_mesh::{ops, SingleVariableFunctionMesh};
 
 fn main() {
     App::new()
@@ -9,11 +9,11 @@ fn main() {
 }
 
 fn circle(x: f32) -> f32 {
-    (1.0 - x.powf(2.0)).powf(0.5)
+    ops::powf(1.0 - ops::powf(x, 2.0), 0.5)
 }
 
 fn squircle(x: f32) -> f32 {
-    (1.0 - (x).abs().powf(4.0)).powf(0.25)
+    ops::powf(1.0 - ops::powf((x).abs(), 4.0), 0.25)
 }
 
 fn straight(_x: f32) -> f32 {
@@ -55,7 +55,7 @@ fn setup(
     commands.spawn((
         Mesh3d(meshes.add(SingleVariableFunctionMesh {
             f1: squircle,
-            f2: |x: f32| -> f32 { (1.0 - (x * 5.0).abs().powf(4.0)).powf(0.25) },
+            f2: |x: f32| -> f32 { ops::powf(1.0 - ops::powf((x * 5.0).abs(), 4.0), 0.25) },
             f2_x_start: -0.2,
             f2_x_end: 0.2,
             ..default()
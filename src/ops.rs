@@ -0,0 +1,58 @@
+//! `std`-or-`libm` wrappers for the float ops used in mesh generation, so
+//! enabling the `libm` feature makes generated meshes bit-reproducible
+//! across platforms.
+
+use bevy::math::Vec3;
+
+/// Computes the arctangent of `x`, in radians in the range `[-pi/2, pi/2]`.
+#[inline]
+pub fn atan(x: f32) -> f32 {
+    #[cfg(feature = "libm")]
+    return libm::atanf(x);
+    #[cfg(not(feature = "libm"))]
+    return x.atan();
+}
+
+/// Computes the tangent of `x` (in radians).
+#[inline]
+pub fn tan(x: f32) -> f32 {
+    #[cfg(feature = "libm")]
+    return libm::tanf(x);
+    #[cfg(not(feature = "libm"))]
+    return x.tan();
+}
+
+/// Raises `x` to the floating point power `y`.
+#[inline]
+pub fn powf(x: f32, y: f32) -> f32 {
+    #[cfg(feature = "libm")]
+    return libm::powf(x, y);
+    #[cfg(not(feature = "libm"))]
+    return x.powf(y);
+}
+
+/// Returns a number that represents the sign of `x`, `1.0` for positive
+/// (including `+0.0`) and `-1.0` for negative (including `-0.0`).
+#[inline]
+pub fn signum(x: f32) -> f32 {
+    #[cfg(feature = "libm")]
+    return libm::copysignf(1.0, x);
+    #[cfg(not(feature = "libm"))]
+    return x.signum();
+}
+
+/// Computes the square root of `x`.
+#[inline]
+pub fn sqrt(x: f32) -> f32 {
+    #[cfg(feature = "libm")]
+    return libm::sqrtf(x);
+    #[cfg(not(feature = "libm"))]
+    return x.sqrt();
+}
+
+/// Normalizes `v` to a length of `1.0`, via [`sqrt`] rather than
+/// `Vec3::normalize` so the result stays under this module's feature gate.
+#[inline]
+pub fn normalize(v: Vec3) -> Vec3 {
+    v / sqrt(v.x * v.x + v.y * v.y + v.z * v.z)
+}